@@ -2,7 +2,7 @@ use chrono::{DurationRound, NaiveDate, TimeDelta};
 use clap::Parser;
 use comfy_table::{
     presets::{ASCII_FULL_CONDENSED, UTF8_FULL_CONDENSED},
-    Cell, Cells, Table,
+    Cell, Cells, Color, Table,
 };
 use itertools::Itertools;
 use open_meteo_rs::forecast::ForecastResultHourly;
@@ -15,58 +15,361 @@ static DOC_STR: &str = "Opening the window will bring indoor humidity closer \
                         to the value indicated in the column corresponding to the \
                         indoor temperature";
 
+static LEGEND_STR: &str = "Legend: cyan <30% too dry · green 30-60% comfortable \
+                        · yellow 60-70% elevated · red >70% condensation/mould risk";
+
 #[derive(Parser)]
 struct Args {
-    lat: f64,
-    lng: f64,
+    /// Latitude of the location to query (resolved via IP geolocation if omitted)
+    lat: Option<f64>,
+    /// Longitude of the location to query (resolved via IP geolocation if omitted)
+    lng: Option<f64>,
+    /// Override the location as "lat,lng", taking precedence over the positional
+    /// lat/lng and the IP-based lookup
+    #[clap(long)]
+    location: Option<String>,
     /// Forces output to use ASCII only
     #[clap(long)]
     ascii: bool,
+    /// Output format
+    #[clap(long, value_enum, default_value = "table")]
+    format: OutputFormat,
+    /// Emit a single compact line suitable for a tiling-WM status bar
+    /// (i3blocks/i3status JSON block protocol) instead of the full tables
+    #[clap(long)]
+    status_bar: bool,
+    /// With --status-bar, print one line and exit instead of refreshing
+    /// periodically
+    #[clap(long)]
+    once: bool,
+    /// With --status-bar, seconds to wait between refreshes
+    #[clap(long, default_value_t = 300)]
+    interval: u64,
+    /// Temperature unit system
+    #[clap(long, value_enum, default_value = "metric")]
+    units: Units,
+    /// Number of hourly forecast entries to show
+    #[clap(long, default_value_t = 10)]
+    hours: usize,
+    /// Number of daily forecast entries to show
+    #[clap(long, default_value_t = 7)]
+    days: usize,
+    /// Current indoor temperature, enables the "should I open my window?"
+    /// recommendation column
+    #[clap(long, requires = "indoor_rh")]
+    indoor_temp: Option<f64>,
+    /// Current indoor relative humidity (%)
+    #[clap(long, requires = "indoor_temp")]
+    indoor_rh: Option<f64>,
+    /// Minimum vapor pressure drop (hPa) required to recommend opening
+    #[clap(long, default_value_t = 0.5)]
+    margin: f64,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Units {
+    Metric,
+    Imperial,
+}
+
+impl Units {
+    fn open_meteo_unit(self) -> open_meteo_rs::forecast::TemperatureUnit {
+        match self {
+            Units::Metric => open_meteo_rs::forecast::TemperatureUnit::Celsius,
+            Units::Imperial => open_meteo_rs::forecast::TemperatureUnit::Fahrenheit,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Units::Metric => "°C",
+            Units::Imperial => "°F",
+        }
+    }
+
+    /// Converts a `TEMP_RANGE` entry (always Celsius) into this unit for display.
+    fn display_temp(self, celsius: f64) -> f64 {
+        match self {
+            Units::Metric => celsius,
+            Units::Imperial => celsius_to_fahrenheit(celsius),
+        }
+    }
+
+    /// Converts a forecast temperature already expressed in this unit back to
+    /// Celsius, since `celsius_sat_pres` must always receive Celsius.
+    fn to_celsius(self, temp: f64) -> f64 {
+        match self {
+            Units::Metric => temp,
+            Units::Imperial => fahrenheit_to_celsius(temp),
+        }
+    }
+}
+
+/// Resolves the location to query, in order of precedence: `--location`,
+/// positional `lat`/`lng`, then an IP-based geolocation lookup.
+async fn resolve_location(args: &Args) -> Result<open_meteo_rs::Location, String> {
+    if let Some(location) = &args.location {
+        let (lat, lng) = parse_location(location)?;
+        return Ok(open_meteo_rs::Location { lat, lng });
+    }
+    match (args.lat, args.lng) {
+        (Some(lat), Some(lng)) => return Ok(open_meteo_rs::Location { lat, lng }),
+        (Some(_), None) | (None, Some(_)) => {
+            return Err("lat and lng must be given together".to_string())
+        }
+        (None, None) => {}
+    }
+    geolocate_by_ip()
+        .await
+        .map_err(|e| format!("could not determine location automatically: {e}"))
+}
+
+fn parse_location(s: &str) -> Result<(f64, f64), String> {
+    let (lat, lng) = s
+        .split_once(',')
+        .ok_or_else(|| format!("invalid --location {s:?}, expected \"lat,lng\""))?;
+    let lat: f64 = lat
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid latitude in --location {s:?}"))?;
+    let lng: f64 = lng
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid longitude in --location {s:?}"))?;
+    Ok((lat, lng))
+}
+
+/// Looks up the caller's approximate coordinates through a no-key IP
+/// geolocation endpoint, as the i3status-rust and yawcli weather front-ends do.
+async fn geolocate_by_ip() -> Result<open_meteo_rs::Location, String> {
+    #[derive(serde::Deserialize)]
+    struct IpGeolocation {
+        latitude: f64,
+        longitude: f64,
+    }
+
+    // `reqwest::get` has no timeout of its own, so a dead network path would
+    // otherwise hang the whole CLI instead of surfacing a clear error.
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let resp = client
+        .get("https://ipapi.co/json/")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json::<IpGeolocation>()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(open_meteo_rs::Location {
+        lat: resp.latitude,
+        lng: resp.longitude,
+    })
+}
+
+fn build_options(
+    location: open_meteo_rs::Location,
+    units: Units,
+    forecast_days: usize,
+) -> open_meteo_rs::forecast::Options {
+    let mut opts = open_meteo_rs::forecast::Options::default();
+    opts.location = location;
+    opts.temperature_unit = Some(units.open_meteo_unit());
+    opts.time_zone = Some("auto".to_owned());
+    opts.forecast_days = Some(forecast_days as _);
+    opts.hourly.push("temperature_2m".into());
+    opts.hourly.push("relative_humidity_2m".into());
+    opts
+}
+
+/// open-meteo rejects `forecast_days` beyond this.
+const MAX_FORECAST_DAYS: usize = 16;
+
+/// Number of days of forecast to request from the API so that both `--days`
+/// and `--hours` are covered, clamped to what open-meteo accepts.
+fn needed_forecast_days(hours: usize, days: usize) -> usize {
+    days.max(hours.div_ceil(24) + 1).min(MAX_FORECAST_DAYS)
 }
 
 #[tokio::main]
 async fn main() {
     let client = open_meteo_rs::Client::new();
-    let mut opts = open_meteo_rs::forecast::Options::default();
 
     // Location
-    let Args { lat, lng, ascii } = Args::parse();
-    opts.location = open_meteo_rs::Location { lat, lng };
-    opts.elevation = Some(63.1.into());
-    opts.temperature_unit = Some(open_meteo_rs::forecast::TemperatureUnit::Celsius);
-    opts.time_zone = Some("auto".to_owned());
-    opts.forecast_days = Some(7);
-    opts.hourly.push("temperature_2m".into());
-    opts.hourly.push("relative_humidity_2m".into());
-    let forecast = client.forecast(opts).await.unwrap();
+    let args = Args::parse();
+    let location = resolve_location(&args).await.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    });
+    let ascii = args.ascii;
+    let units = args.units;
+
+    let sat_press: [f64; TEMP_RANGE.len()] =
+        std::array::from_fn(|i| celsius_sat_pres(TEMP_RANGE[i]));
+
+    let indoor = args
+        .indoor_temp
+        .zip(args.indoor_rh)
+        .map(|(indoor_temp, indoor_rh)| IndoorConditions::from_args(indoor_temp, indoor_rh, units));
+
+    if args.status_bar {
+        run_status_bar(
+            &client,
+            location,
+            units,
+            &sat_press,
+            indoor,
+            args.margin,
+            args.once,
+            args.interval,
+        )
+        .await;
+        return;
+    }
+
+    let forecast_days = needed_forecast_days(args.hours, args.days);
+    let forecast = match client
+        .forecast(build_options(location, units, forecast_days))
+        .await
+    {
+        Ok(forecast) => forecast,
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    };
 
     let this_day_and_hour = chrono::offset::Local::now()
         .naive_local()
         .duration_trunc(TimeDelta::hours(1))
         .unwrap();
 
-    let hourly_forecast = forecast
+    let hourly_items: Vec<ForeCastItem> = forecast
         .hourly
         .iter()
         .flatten()
         .skip_while(|forecast| forecast.datetime < this_day_and_hour)
         .map(ForeCastItem::from_api)
-        .take(10);
-    let t_h = print_one_table(hourly_forecast, TableType::Hourly, ascii);
-    println!("{}\n", DOC_STR);
-    println!("{t_h}\n");
+        .take(args.hours)
+        .collect();
 
     let daily_groups = forecast
         .hourly
         .iter()
         .flatten()
         .chunk_by(|item| item.datetime.date());
-    let daily_forcast_avg = daily_groups
+    let daily_items: Vec<ForeCastItem> = daily_groups
         .into_iter()
         .map(|(date, group)| average_daily(date, group))
-        .take(7);
-    let t_d = print_one_table(daily_forcast_avg, TableType::Daily, ascii);
-    println!("{t_d}");
+        .take(args.days)
+        .collect();
+
+    match args.format {
+        OutputFormat::Table => {
+            let t_h = print_one_table(
+                &hourly_items,
+                TableType::Hourly,
+                &sat_press,
+                units,
+                indoor.as_ref(),
+                args.margin,
+                ascii,
+            );
+            println!("{}\n", DOC_STR);
+            if !ascii {
+                println!("{}\n", LEGEND_STR);
+            }
+            if let Some(indoor) = &indoor {
+                println!(
+                    "Indoor dew point: {:.1}{}\n",
+                    units.display_temp(indoor.dew_point_celsius),
+                    units.label()
+                );
+            }
+            println!("{t_h}\n");
+
+            let t_d = print_one_table(
+                &daily_items,
+                TableType::Daily,
+                &sat_press,
+                units,
+                indoor.as_ref(),
+                args.margin,
+                ascii,
+            );
+            println!("{t_d}");
+        }
+        OutputFormat::Json => print_json(
+            &hourly_items,
+            &daily_items,
+            &sat_press,
+            units,
+            indoor.as_ref(),
+            args.margin,
+        ),
+        OutputFormat::Csv => print_csv(
+            &hourly_items,
+            &daily_items,
+            &sat_press,
+            units,
+            indoor.as_ref(),
+            args.margin,
+        )
+        .unwrap_or_else(|e| eprintln!("error writing CSV: {e}")),
+    }
+}
+
+/// Prints one i3blocks/i3status JSON block per refresh for the current (or
+/// next) forecast hour, looping every `interval` seconds unless `once` is set.
+async fn run_status_bar(
+    client: &open_meteo_rs::Client,
+    location: open_meteo_rs::Location,
+    units: Units,
+    sat_press: &[f64; TEMP_RANGE.len()],
+    indoor: Option<IndoorConditions>,
+    margin: f64,
+    once: bool,
+    interval: u64,
+) {
+    loop {
+        match client
+            .forecast(build_options(location.clone(), units, 2))
+            .await
+        {
+            Ok(forecast) => {
+                let this_day_and_hour = chrono::offset::Local::now()
+                    .naive_local()
+                    .duration_trunc(TimeDelta::hours(1))
+                    .unwrap();
+                let next_item = forecast
+                    .hourly
+                    .iter()
+                    .flatten()
+                    .find(|item| item.datetime >= this_day_and_hour)
+                    .map(ForeCastItem::from_api);
+                match next_item {
+                    Some(item) => println!(
+                        "{}",
+                        status_bar_block(&item, sat_press, units, indoor.as_ref(), margin)
+                    ),
+                    None => eprintln!("error: no upcoming forecast hour available"),
+                }
+            }
+            Err(e) => eprintln!("error: {e}"),
+        }
+        if once {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+    }
 }
 
 fn extract_temp_rh(item: &ForecastResultHourly) -> (f64, f64) {
@@ -130,34 +433,55 @@ impl ForeCastItem {
     }
 }
 
+/// Computes the relative humidity that would result at each `TEMP_RANGE`
+/// target temperature if the outdoor air described by `item` were brought
+/// indoors, given the pre-computed saturation pressures for `TEMP_RANGE`.
+fn target_rhs(item: &ForeCastItem, sat_press: &[f64; TEMP_RANGE.len()], units: Units) -> Vec<f64> {
+    let forecast_sat_pres = celsius_sat_pres(units.to_celsius(item.temperature));
+    let forecast_vapor_pressure = item.relative_humidity * forecast_sat_pres;
+    sat_press
+        .iter()
+        .map(|&sat_pres| forecast_vapor_pressure / sat_pres)
+        .collect()
+}
+
 fn print_one_table(
-    forecast: impl Iterator<Item = ForeCastItem>,
+    forecast: &[ForeCastItem],
     table_type: TableType,
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
     ascii: bool,
 ) -> Table {
     let mut table = Table::new();
-    let sat_press: [f64; TEMP_RANGE.len()] =
-        std::array::from_fn(|i| celsius_sat_pres(TEMP_RANGE[i]));
     let mut header =
         vec![Cell::new(String::from(table_type.name()))
             .add_attribute(comfy_table::Attribute::Italic)];
     for temp in TEMP_RANGE {
-        let cell = Cell::new(format!("{temp:.1}°C")).add_attribute(comfy_table::Attribute::Bold);
+        let cell = Cell::new(format!("{:.1}{}", units.display_temp(temp), units.label()))
+            .add_attribute(comfy_table::Attribute::Bold);
         header.push(cell);
     }
+    if indoor.is_some() {
+        header.push(
+            Cell::new("Open?").add_attribute(comfy_table::Attribute::Bold),
+        );
+    }
     table.set_header(Cells(header));
     for forecast_item in forecast {
         let mut row: Vec<Cell> = vec![format!(
-            "{datetime} ({temp:.1}°C)",
+            "{datetime} ({temp:.1}{unit})",
             datetime = forecast_item.datetime_repr,
-            temp = forecast_item.temperature
+            temp = forecast_item.temperature,
+            unit = units.label(),
         )
         .into()];
-        let forecast_sat_pres = celsius_sat_pres(forecast_item.temperature);
-        let forecast_vapor_pressure = forecast_item.relative_humidity * forecast_sat_pres;
-        for &sat_pres in &sat_press {
-            let rh = forecast_vapor_pressure / sat_pres;
-            row.push(rh_cell(rh));
+        for rh in target_rhs(forecast_item, sat_press, units) {
+            row.push(rh_cell(rh, ascii));
+        }
+        if let Some(indoor) = indoor {
+            row.push(decide(forecast_item, indoor, units, margin).cell(ascii));
         }
         table.add_row(Cells(row));
     }
@@ -169,8 +493,389 @@ fn print_one_table(
     table
 }
 
-fn rh_cell(rh: f64) -> Cell {
-    format!("{rh:.1}%").into()
+#[derive(serde::Serialize)]
+struct TargetRh {
+    target_temperature: f64,
+    relative_humidity: f64,
+}
+
+#[derive(serde::Serialize)]
+struct ForecastRow {
+    datetime: String,
+    forecast_temperature: f64,
+    forecast_relative_humidity: f64,
+    relative_humidity_by_target_temp: Vec<TargetRh>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_recommendation: Option<OpenDecision>,
+}
+
+/// The indoor reading the report was computed against, in display `Units`.
+#[derive(serde::Serialize)]
+struct IndoorReading {
+    temperature: f64,
+    relative_humidity: f64,
+    dew_point: f64,
+}
+
+impl IndoorReading {
+    fn from_conditions(indoor: &IndoorConditions, units: Units) -> Self {
+        IndoorReading {
+            temperature: units.display_temp(indoor.temperature_celsius),
+            relative_humidity: indoor.relative_humidity,
+            dew_point: units.display_temp(indoor.dew_point_celsius),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Report {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indoor: Option<IndoorReading>,
+    hourly: Vec<ForecastRow>,
+    daily: Vec<ForecastRow>,
+}
+
+fn forecast_row(
+    item: &ForeCastItem,
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
+) -> ForecastRow {
+    let relative_humidity_by_target_temp = TEMP_RANGE
+        .iter()
+        .zip(target_rhs(item, sat_press, units))
+        .map(|(&target_temperature, relative_humidity)| TargetRh {
+            target_temperature: units.display_temp(target_temperature),
+            relative_humidity,
+        })
+        .collect();
+    ForecastRow {
+        datetime: item.datetime_repr.clone(),
+        forecast_temperature: item.temperature,
+        forecast_relative_humidity: item.relative_humidity,
+        relative_humidity_by_target_temp,
+        open_recommendation: indoor.map(|indoor| decide(item, indoor, units, margin)),
+    }
+}
+
+fn print_json(
+    hourly: &[ForeCastItem],
+    daily: &[ForeCastItem],
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
+) {
+    let report = Report {
+        indoor: indoor.map(|indoor| IndoorReading::from_conditions(indoor, units)),
+        hourly: hourly
+            .iter()
+            .map(|item| forecast_row(item, sat_press, units, indoor, margin))
+            .collect(),
+        daily: daily
+            .iter()
+            .map(|item| forecast_row(item, sat_press, units, indoor, margin))
+            .collect(),
+    };
+    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+}
+
+/// The `csv` crate's `Serialize` implementation rejects `serialize_map`
+/// (flattening a map into a record isn't supported), so each `TEMP_RANGE`
+/// entry is written as its own named column via `write_record` instead of
+/// through serde.
+fn csv_header(units: Units, indoor: bool) -> Vec<String> {
+    let mut header = vec![
+        "series".to_string(),
+        "datetime".to_string(),
+        "forecast_temperature".to_string(),
+        "forecast_relative_humidity".to_string(),
+    ];
+    for &temp in &TEMP_RANGE {
+        header.push(format!("rh_at_{:.1}{}", units.display_temp(temp), units.label()));
+    }
+    if indoor {
+        header.push("indoor_temperature".to_string());
+        header.push("indoor_relative_humidity".to_string());
+        header.push("indoor_dew_point".to_string());
+        header.push("should_open".to_string());
+        header.push("condensation_risk".to_string());
+    }
+    header
+}
+
+fn csv_record(
+    series: &str,
+    item: &ForeCastItem,
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
+) -> Vec<String> {
+    let mut record = vec![
+        series.to_string(),
+        item.datetime_repr.clone(),
+        item.temperature.to_string(),
+        item.relative_humidity.to_string(),
+    ];
+    for rh in target_rhs(item, sat_press, units) {
+        record.push(rh.to_string());
+    }
+    if let Some(indoor) = indoor {
+        let decision = decide(item, indoor, units, margin);
+        record.push(units.display_temp(indoor.temperature_celsius).to_string());
+        record.push(indoor.relative_humidity.to_string());
+        record.push(units.display_temp(indoor.dew_point_celsius).to_string());
+        record.push(decision.should_open.to_string());
+        record.push(decision.condensation_risk.to_string());
+    }
+    record
+}
+
+fn print_csv(
+    hourly: &[ForeCastItem],
+    daily: &[ForeCastItem],
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
+) -> Result<(), csv::Error> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record(csv_header(units, indoor.is_some()))?;
+    for item in hourly {
+        writer.write_record(csv_record("hourly", item, sat_press, units, indoor, margin))?;
+    }
+    for item in daily {
+        writer.write_record(csv_record("daily", item, sat_press, units, indoor, margin))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Current indoor reading, expressed in Celsius/hPa regardless of `--units`.
+#[derive(Clone, Copy)]
+struct IndoorConditions {
+    temperature_celsius: f64,
+    relative_humidity: f64,
+    vapor_pressure: f64,
+    dew_point_celsius: f64,
+}
+
+impl IndoorConditions {
+    fn from_args(indoor_temp: f64, indoor_rh: f64, units: Units) -> Self {
+        let temp_celsius = units.to_celsius(indoor_temp);
+        IndoorConditions {
+            temperature_celsius: temp_celsius,
+            relative_humidity: indoor_rh,
+            vapor_pressure: vapor_pressure_hpa(temp_celsius, indoor_rh),
+            dew_point_celsius: magnus_dew_point(temp_celsius, indoor_rh),
+        }
+    }
+}
+
+/// `rust_steam::p_sat` returns a IAPWS-IF97 saturation pressure in MPa, not
+/// hPa, so it must be converted before comparing against `--margin` (which is
+/// documented and accepted in hPa).
+const MPA_TO_HPA: f64 = 10_000.;
+
+/// Vapor pressure in hPa for air at `celsius`/`rh`.
+fn vapor_pressure_hpa(celsius: f64, rh: f64) -> f64 {
+    rh / 100. * celsius_sat_pres(celsius) * MPA_TO_HPA
+}
+
+/// Dew point in Celsius via the Magnus formula.
+fn magnus_dew_point(temp_celsius: f64, rh: f64) -> f64 {
+    const A: f64 = 17.62;
+    const B: f64 = 243.12;
+    let alpha = (rh / 100.).ln() + A * temp_celsius / (B + temp_celsius);
+    B * alpha / (A - alpha)
+}
+
+/// Whether opening the window during `item` would dry the room, and whether
+/// doing so risks condensation on cold surfaces.
+#[derive(serde::Serialize)]
+struct OpenDecision {
+    should_open: bool,
+    condensation_risk: bool,
+}
+
+fn decide(item: &ForeCastItem, indoor: &IndoorConditions, units: Units, margin: f64) -> OpenDecision {
+    let outdoor_celsius = units.to_celsius(item.temperature);
+    let outdoor_vapor_pressure = vapor_pressure_hpa(outdoor_celsius, item.relative_humidity);
+    OpenDecision {
+        should_open: outdoor_vapor_pressure + margin < indoor.vapor_pressure,
+        condensation_risk: outdoor_celsius <= indoor.dew_point_celsius,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_open_flips_true_for_cold_dry_air() {
+        // Warm, humid indoor air; cold, comparatively dry outdoor air should
+        // recommend opening the window. 5°C is below the indoor dew point
+        // (about 11.6°C for 21°C/55%), so condensation risk is flagged too.
+        let indoor = IndoorConditions::from_args(21., 55., Units::Metric);
+        let outdoor = ForeCastItem {
+            datetime_repr: "test".to_string(),
+            temperature: 5.,
+            relative_humidity: 80.,
+        };
+        let decision = decide(&outdoor, &indoor, Units::Metric, 0.5);
+        assert!(decision.should_open);
+        assert!(decision.condensation_risk);
+    }
+
+    #[test]
+    fn should_open_false_for_humid_outdoor_air() {
+        // Outdoor air as humid as indoors shouldn't be recommended.
+        let indoor = IndoorConditions::from_args(21., 55., Units::Metric);
+        let outdoor = ForeCastItem {
+            datetime_repr: "test".to_string(),
+            temperature: 21.,
+            relative_humidity: 55.,
+        };
+        let decision = decide(&outdoor, &indoor, Units::Metric, 0.5);
+        assert!(!decision.should_open);
+    }
+}
+
+impl OpenDecision {
+    fn cell(&self, ascii: bool) -> Cell {
+        let cell = Cell::new(match (self.should_open, self.condensation_risk) {
+            (_, true) => "no ⚠",
+            (true, false) => "yes",
+            (false, false) => "no",
+        });
+        if ascii {
+            return cell;
+        }
+        if self.condensation_risk {
+            cell.fg(Color::Red)
+        } else if self.should_open {
+            cell.fg(Color::Green)
+        } else {
+            cell
+        }
+    }
+}
+
+fn rh_cell(rh: f64, ascii: bool) -> Cell {
+    let cell = Cell::new(format!("{rh:.1}%"));
+    if ascii {
+        cell
+    } else {
+        cell.fg(comfort_band(rh).table_color())
+    }
+}
+
+/// Comfort/mould-risk band a relative humidity percentage falls into.
+#[derive(Clone, Copy)]
+enum ComfortBand {
+    TooDry,
+    Comfortable,
+    Elevated,
+    MouldRisk,
+}
+
+fn comfort_band(rh: f64) -> ComfortBand {
+    if rh < 30. {
+        ComfortBand::TooDry
+    } else if rh < 60. {
+        ComfortBand::Comfortable
+    } else if rh < 70. {
+        ComfortBand::Elevated
+    } else {
+        ComfortBand::MouldRisk
+    }
+}
+
+impl ComfortBand {
+    fn table_color(self) -> Color {
+        match self {
+            ComfortBand::TooDry => Color::Cyan,
+            ComfortBand::Comfortable => Color::Green,
+            ComfortBand::Elevated => Color::Yellow,
+            ComfortBand::MouldRisk => Color::Red,
+        }
+    }
+
+    fn status_bar_color(self) -> &'static str {
+        match self {
+            ComfortBand::TooDry => "#00ffff",
+            ComfortBand::Comfortable => "#00ff00",
+            ComfortBand::Elevated => "#ffff00",
+            ComfortBand::MouldRisk => "#ff0000",
+        }
+    }
+
+    /// Whether this band represents a hour worth opening the window for.
+    fn should_open(self) -> bool {
+        matches!(self, ComfortBand::TooDry | ComfortBand::Comfortable)
+    }
+}
+
+/// Index into `TEMP_RANGE` of the entry closest to `target`.
+fn closest_target_temp_index(target: f64) -> usize {
+    TEMP_RANGE
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - target).abs().partial_cmp(&(*b - target).abs()).unwrap())
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+#[derive(serde::Serialize)]
+struct StatusBarBlock {
+    full_text: String,
+    color: &'static str,
+}
+
+/// Renders the i3blocks/i3status JSON block for `item`. When an indoor
+/// reading is available, uses the same `decide()` computation as the
+/// table/json/csv outputs; otherwise falls back to the generic comfort-band
+/// heuristic against a typical indoor temperature.
+fn status_bar_block(
+    item: &ForeCastItem,
+    sat_press: &[f64; TEMP_RANGE.len()],
+    units: Units,
+    indoor: Option<&IndoorConditions>,
+    margin: f64,
+) -> String {
+    let (verb, color, rh) = match indoor {
+        Some(indoor) => {
+            let decision = decide(item, indoor, units, margin);
+            let band = if decision.condensation_risk {
+                ComfortBand::MouldRisk
+            } else if decision.should_open {
+                ComfortBand::Comfortable
+            } else {
+                ComfortBand::Elevated
+            };
+            let verb = if decision.should_open && !decision.condensation_risk {
+                "open"
+            } else {
+                "keep shut"
+            };
+            (verb, band.status_bar_color(), item.relative_humidity)
+        }
+        None => {
+            const TYPICAL_INDOOR_TEMP: f64 = 20.;
+            let idx = closest_target_temp_index(TYPICAL_INDOOR_TEMP);
+            let rh = target_rhs(item, sat_press, units)[idx];
+            let band = comfort_band(rh);
+            let verb = if band.should_open() { "open" } else { "keep shut" };
+            (verb, band.status_bar_color(), rh)
+        }
+    };
+    let block = StatusBarBlock {
+        full_text: format!("🪟 {verb} (RH→{rh:.0}%)"),
+        color,
+    };
+    serde_json::to_string(&block).unwrap()
 }
 
 fn celsius_sat_pres(celsius: f64) -> f64 {
@@ -180,3 +885,11 @@ fn celsius_sat_pres(celsius: f64) -> f64 {
 fn celsius_to_kelvin(celsius: f64) -> f64 {
     celsius + 273.15
 }
+
+fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9. / 5. + 32.
+}
+
+fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.) * 5. / 9.
+}